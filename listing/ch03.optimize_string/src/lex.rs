@@ -30,16 +30,57 @@ pub enum Token {
     // name of variables or table keys
     Name(String),
 
+    // a single byte the lexer didn't recognize
+    Unknown(u8),
+    // a malformed token, recorded as data instead of aborting the lexer
+    Error(LexError),
+
     // end
     Eos,
 }
 // ANCHOR_END: token
 
+// problems found while lexing; collected rather than panicking so the
+// lexer can keep producing tokens after bad input
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LexError {
+    UnfinishedString,
+    MalformedNumber,
+    UnfinishedLongBracket,
+    InvalidEscape,
+}
+
+// a position in the source, tracked as the lexer consumes bytes
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Pos {
+    pub line: u32,
+    pub column: u32,
+    pub offset: u32,
+}
+
+// a token together with the span of source it was lexed from
+#[derive(Debug, PartialEq)]
+pub struct Spanned<T> {
+    pub token: T,
+    pub start: Pos,
+    pub end: Pos,
+}
+
+impl<T> Spanned<T> {
+    fn new(token: T, start: Pos, end: Pos) -> Self {
+        Spanned { token, start, end }
+    }
+}
+
 #[derive(Debug)]
 // ANCHOR: lex
 pub struct Lex<R: Read> {
     input: Peekable::<Bytes::<R>>,
-    ahead: Token,
+    ahead: Spanned<Token>,
+    line: u32,
+    column: u32,
+    offset: u32,
+    errors: Vec<(LexError, Pos)>,
 }
 // ANCHOR_END: lex
 
@@ -47,31 +88,61 @@ impl<R: Read> Lex<R> {
     pub fn new(input: R) -> Self {
         Lex {
             input: input.bytes().peekable(),
-            ahead: Token::Eos,
+            ahead: Spanned::new(Token::Eos, Pos::default(), Pos::default()),
+            line: 1,
+            column: 1,
+            offset: 0,
+            errors: Vec::new(),
         }
     }
 
+    // diagnostics collected so far, so callers can report them all at
+    // once instead of dying on the first
+    pub fn errors(&self) -> &[(LexError, Pos)] {
+        &self.errors
+    }
+
 // ANCHOR: peek_next
-    pub fn next(&mut self) -> Token {
-        if self.ahead == Token::Eos {
+    pub fn next(&mut self) -> Spanned<Token> {
+        if self.ahead.token == Token::Eos {
             self.do_next()
         } else {
-            mem::replace(&mut self.ahead, Token::Eos)
+            mem::replace(&mut self.ahead, Spanned::new(Token::Eos, Pos::default(), Pos::default()))
         }
     }
 
     pub fn peek(&mut self) -> &Token {
-        if self.ahead == Token::Eos {
+        if self.ahead.token == Token::Eos {
             self.ahead = self.do_next();
         }
-        &self.ahead
+        &self.ahead.token
     }
 // ANCHOR_END: peek_next
 
-    fn do_next(&mut self) -> Token {
+    // current position, i.e. the position right before the next byte
+    fn pos(&self) -> Pos {
+        Pos { line: self.line, column: self.column, offset: self.offset }
+    }
+
+    // read one token, wrapping it with the span it was lexed from; loops
+    // past whitespace/comments so `start` always lands on the real token,
+    // never on leading trivia
+    fn do_next(&mut self) -> Spanned<Token> {
+        loop {
+            let start = self.pos();
+            if let Some(token) = self.do_next_token() {
+                let end = self.pos();
+                return Spanned::new(token, start, end);
+            }
+        }
+    }
+
+    // reads one token, or None if only trivia (whitespace/comment) was
+    // consumed, in which case the caller re-snapshots `start` and retries
+    fn do_next_token(&mut self) -> Option<Token> {
         let byt = self.next_byte();
-        match byt {
-            b'\n' | b'\r' | b'\t' | b' ' => self.do_next(),
+        let token = match byt {
+            b'\n' | b'\r' | b'\t' | b' ' => return None,
             b'+' => Token::Add,
             b'*' => Token::Mul,
             b'%' => Token::Mod,
@@ -83,7 +154,10 @@ impl<R: Read> Lex<R> {
             b')' => Token::ParR,
             b'{' => Token::CurlyL,
             b'}' => Token::CurlyR,
-            b'[' => Token::SqurL,
+            b'[' => match self.read_long_bracket() {
+                Some(s) => Token::String(s),
+                None => Token::SqurL,
+            },
             b']' => Token::SqurR,
             b';' => Token::SemiColon,
             b',' => Token::Comma,
@@ -115,7 +189,7 @@ impl<R: Read> Lex<R> {
                 if self.peek_byte() == b'-' {
                     self.next_byte();
                     self.read_comment();
-                    self.do_next()
+                    return None;
                 } else {
                     Token::Sub
                 }
@@ -123,8 +197,15 @@ impl<R: Read> Lex<R> {
             b'0'..=b'9' => self.read_number(byt),
             b'A'..=b'Z' | b'a'..=b'z' | b'_' => self.read_name(byt),
             b'\0' => Token::Eos, // TODO
-            _ => panic!("invalid char {byt}"),
-        }
+            _ => Token::Unknown(byt),
+        };
+        Some(token)
+    }
+
+    // record a diagnostic at the current position, without aborting lexing
+    fn record_error(&mut self, err: LexError) {
+        let pos = self.pos();
+        self.errors.push((err, pos));
     }
 
     fn peek_byte(&mut self) -> u8 {
@@ -135,11 +216,20 @@ impl<R: Read> Lex<R> {
         }
     }
     fn next_byte(&mut self) -> u8 {
-        match self.input.next() {
+        let byt = match self.input.next() {
             Some(Ok(byt)) => byt,
             Some(_) => panic!("lex read error"),
-            None => b'\0',
+            None => return b'\0',
+        };
+
+        self.offset += 1;
+        if byt == b'\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
         }
+        byt
     }
 
     fn check_ahead(&mut self, ahead: u8, long: Token, short: Token) -> Token {
@@ -191,7 +281,8 @@ impl<R: Read> Lex<R> {
         // check following
         let fch = self.peek_byte();
         if (fch as char).is_alphabetic() || fch == b'.' {
-            panic!("malformat number");
+            self.record_error(LexError::MalformedNumber);
+            return Token::Error(LexError::MalformedNumber);
         }
 
         Token::Integer(n)
@@ -211,23 +302,142 @@ impl<R: Read> Lex<R> {
                 break;
             }
         }
-        Token::Float(i as f64 + n as f64 / x)
+        let f = i as f64 + n as f64 / x;
+        match self.peek_byte() {
+            b'e' | b'E' => self.read_number_exp(f),
+            _ => Token::Float(f),
+        }
     }
-    fn read_number_exp(&mut self, _: f64) -> Token {
-        self.next_byte(); // skip 'e'
-        todo!("lex number exp")
+    fn read_number_exp(&mut self, base: f64) -> Token {
+        self.next_byte(); // skip 'e'/'E'
+
+        let neg = self.read_exp_sign();
+        let (exp, any_digit) = self.read_digits(10);
+        if !any_digit {
+            self.record_error(LexError::MalformedNumber);
+            return Token::Error(LexError::MalformedNumber);
+        }
+
+        let exp = if neg { -exp } else { exp };
+        Token::Float(base * 10f64.powi(exp as i32))
     }
     fn read_heximal(&mut self) -> Token {
-        self.next_byte(); // skip 'x'
-        todo!("lex heximal")
+        self.next_byte(); // skip 'x'/'X'
+
+        let (n, int_digits) = self.read_digits(16);
+        let token = if self.peek_byte() == b'.' {
+            self.next_byte();
+            let mut frac: i64 = 0;
+            let mut scale = 1f64;
+            let mut frac_digits = false;
+            loop {
+                let byt = self.peek_byte();
+                if let Some(d) = char::to_digit(byt as char, 16) {
+                    self.next_byte();
+                    frac = frac * 16 + d as i64;
+                    scale *= 16.0;
+                    frac_digits = true;
+                } else {
+                    break;
+                }
+            }
+            self.read_heximal_exp(n as f64 + frac as f64 / scale, int_digits || frac_digits)
+        } else {
+            match self.peek_byte() {
+                b'p' | b'P' => self.read_heximal_exp(n as f64, int_digits),
+                _ => {
+                    if !int_digits {
+                        self.record_error(LexError::MalformedNumber);
+                        return Token::Error(LexError::MalformedNumber);
+                    }
+                    Token::Integer(n)
+                }
+            }
+        };
+
+        // same trailing-char rejection read_number applies to decimal
+        // integers: a hex literal directly followed by a letter or '.' is a
+        // malformed number, not two separate tokens
+        if let Token::Error(_) = token {
+            return token;
+        }
+        let fch = self.peek_byte();
+        if (fch as char).is_alphabetic() || fch == b'.' {
+            self.record_error(LexError::MalformedNumber);
+            return Token::Error(LexError::MalformedNumber);
+        }
+        token
+    }
+    // a hexadecimal mantissa has been read; consume an optional binary
+    // exponent introduced by 'p'/'P' (value = mantissa * 2^exp). `mantissa_has_digits`
+    // tracks whether any digit was seen across the int+frac parts, since a
+    // mantissa with no digits at all (e.g. "0xp3", "0x.p3", "0x.") is
+    // malformed regardless of whether a 'p' exponent follows
+    fn read_heximal_exp(&mut self, mantissa: f64, mantissa_has_digits: bool) -> Token {
+        if self.peek_byte() != b'p' && self.peek_byte() != b'P' {
+            if !mantissa_has_digits {
+                self.record_error(LexError::MalformedNumber);
+                return Token::Error(LexError::MalformedNumber);
+            }
+            return Token::Float(mantissa);
+        }
+        self.next_byte(); // skip 'p'/'P'
+
+        let neg = self.read_exp_sign();
+        let (exp, any_digit) = self.read_digits(10);
+        if !any_digit || !mantissa_has_digits {
+            self.record_error(LexError::MalformedNumber);
+            return Token::Error(LexError::MalformedNumber);
+        }
+
+        let exp = if neg { -exp } else { exp };
+        Token::Float(mantissa * 2f64.powi(exp as i32))
+    }
+
+    // an optional '+'/'-' sign before an exponent; returns whether it's negative
+    fn read_exp_sign(&mut self) -> bool {
+        match self.peek_byte() {
+            b'+' => { self.next_byte(); false }
+            b'-' => { self.next_byte(); true }
+            _ => false,
+        }
+    }
+    // read a run of digits in the given radix into an integer, along with
+    // whether any digit was actually read
+    fn read_digits(&mut self, radix: u32) -> (i64, bool) {
+        let mut n: i64 = 0;
+        let mut any_digit = false;
+        loop {
+            let byt = self.peek_byte();
+            if let Some(d) = char::to_digit(byt as char, radix) {
+                self.next_byte();
+                n = n * radix as i64 + d as i64;
+                any_digit = true;
+            } else {
+                break;
+            }
+        }
+        (n, any_digit)
     }
 
     fn read_string(&mut self, quote: u8) -> Token {
         let mut s = Vec::new();
         loop {
             match self.next_byte() {
-                b'\n' | b'\0' => panic!("unfinished string"),
-                b'\\' => todo!("escape"),
+                b'\n' | b'\0' => {
+                    self.record_error(LexError::UnfinishedString);
+                    return Token::Error(LexError::UnfinishedString);
+                }
+                b'\\' => {
+                    if !self.read_escape(&mut s) {
+                        // the escape itself already recorded InvalidEscape;
+                        // keep scanning to the closing quote (discarding the
+                        // rest of the string) so the error doesn't desync
+                        // the remainder of the line into bogus tokens
+                        self.skip_to_string_end(quote);
+                        return Token::Error(LexError::InvalidEscape);
+                    }
+                }
                 byt if byt == quote => break,
                 byt => s.push(byt),
             }
@@ -235,6 +445,108 @@ impl<R: Read> Lex<R> {
         Token::String(s)
     }
 
+    // an invalid escape has already been reported; discard the rest of the
+    // string (no further escape processing) up to the closing quote,
+    // newline, or EOF so the lexer resumes at a sane position afterwards
+    fn skip_to_string_end(&mut self, quote: u8) {
+        loop {
+            match self.next_byte() {
+                b'\n' | b'\0' => break,
+                byt if byt == quote => break,
+                _ => {}
+            }
+        }
+    }
+
+    // a '\' has just been consumed; append the escaped bytes to `s`,
+    // returning false (after recording an error) on an invalid escape
+    fn read_escape(&mut self, s: &mut Vec<u8>) -> bool {
+        match self.next_byte() {
+            b'a' => s.push(0x07),
+            b'b' => s.push(0x08),
+            b'f' => s.push(0x0C),
+            b'n' => s.push(b'\n'),
+            b'r' => s.push(b'\r'),
+            b't' => s.push(b'\t'),
+            b'v' => s.push(0x0B),
+            b'\\' => s.push(b'\\'),
+            b'"' => s.push(b'"'),
+            b'\'' => s.push(b'\''),
+            b'\n' => s.push(b'\n'),
+            b'x' => {
+                let mut v: u32 = 0;
+                for _ in 0..2 {
+                    match char::to_digit(self.peek_byte() as char, 16) {
+                        Some(d) => {
+                            self.next_byte();
+                            v = v * 16 + d;
+                        }
+                        None => {
+                            self.record_error(LexError::InvalidEscape);
+                            return false;
+                        }
+                    }
+                }
+                s.push(v as u8);
+            }
+            b'z' => {
+                loop {
+                    match self.peek_byte() {
+                        b' ' | b'\t' | b'\n' | b'\r' => { self.next_byte(); }
+                        _ => break,
+                    }
+                }
+            }
+            b'u' => {
+                if self.peek_byte() != b'{' {
+                    self.record_error(LexError::InvalidEscape);
+                    return false;
+                }
+                self.next_byte();
+
+                let (v, any_digit) = self.read_digits(16);
+                if !any_digit || self.peek_byte() != b'}' {
+                    self.record_error(LexError::InvalidEscape);
+                    return false;
+                }
+                self.next_byte();
+
+                match char::from_u32(v as u32) {
+                    Some(ch) => {
+                        let mut buf = [0u8; 4];
+                        s.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+                    }
+                    None => {
+                        self.record_error(LexError::InvalidEscape);
+                        return false;
+                    }
+                }
+            }
+            byt @ b'0'..=b'9' => {
+                let mut v = (byt - b'0') as u32;
+                for _ in 0..2 {
+                    match char::to_digit(self.peek_byte() as char, 10) {
+                        Some(d) => {
+                            self.next_byte();
+                            v = v * 10 + d;
+                        }
+                        None => break,
+                    }
+                }
+                if v > 255 {
+                    self.record_error(LexError::InvalidEscape);
+                    return false;
+                }
+                s.push(v as u8);
+            }
+            _ => {
+                self.record_error(LexError::InvalidEscape);
+                return false;
+            }
+        }
+        true
+    }
+
     fn read_name(&mut self, first: u8) -> Token {
         let mut s = String::new();
         s.push(first as char);
@@ -279,15 +591,256 @@ impl<R: Read> Lex<R> {
     // '--' has been read
     fn read_comment(&mut self) {
         match self.next_byte() {
-            b'[' => todo!("long comment"),
-            _ => { // line comment
-                loop {
-                    let byt = self.next_byte();
-                    if byt == b'\n' || byt == b'\0' {
+            b'[' => {
+                // only a real, *terminated* long bracket ("[[" or "[==[ ... ]==]")
+                // is a long comment; an unopened ('--[foo') or unterminated
+                // ('--[==foo') attempt is just ordinary comment text, so it
+                // still needs to fall back to discarding the rest of the line
+                let errors_before = self.errors.len();
+                let opened = self.read_long_bracket();
+                if opened.is_none() || self.errors.len() > errors_before {
+                    self.read_line_comment();
+                }
+            }
+            _ => self.read_line_comment(),
+        }
+    }
+    fn read_line_comment(&mut self) {
+        loop {
+            let byt = self.next_byte();
+            if byt == b'\n' || byt == b'\0' {
+                break;
+            }
+        }
+    }
+
+    // a '[' has just been consumed; determine whether it opens a long
+    // bracket ("[[", "[=[", "[==[", ...) and if so, read its body verbatim.
+    //
+    // with only 1-byte lookahead, a run of '=' can't be pushed back once
+    // read: if no second '[' shows up with `level == 0`, that single byte
+    // is still unread and `None` lets the caller fall back to `Token::SqurL`
+    // as if nothing happened. But once `level > 0` the '=' run has already
+    // been consumed from the input and can't be un-consumed into separate
+    // tokens, so this is reported as `UnfinishedLongBracket` with the
+    // consumed '=' bytes surfaced as the token's string payload rather than
+    // silently discarded.
+    fn read_long_bracket(&mut self) -> Option<Vec<u8>> {
+        let mut level = 0;
+        while self.peek_byte() == b'=' {
+            self.next_byte();
+            level += 1;
+        }
+        if self.peek_byte() != b'[' {
+            if level == 0 {
+                return None;
+            }
+            self.record_error(LexError::UnfinishedLongBracket);
+            return Some(std::iter::repeat(b'=').take(level).collect());
+        }
+        self.next_byte(); // consume the second '['
+
+        // a newline immediately after the opening bracket is skipped
+        match self.peek_byte() {
+            b'\n' => { self.next_byte(); }
+            b'\r' => {
+                self.next_byte();
+                if self.peek_byte() == b'\n' {
+                    self.next_byte();
+                }
+            }
+            _ => {}
+        }
+
+        let mut s = Vec::new();
+        loop {
+            match self.next_byte() {
+                b'\0' => {
+                    self.record_error(LexError::UnfinishedLongBracket);
+                    break;
+                }
+                b']' => {
+                    let mut eqs = 0;
+                    while self.peek_byte() == b'=' {
+                        self.next_byte();
+                        eqs += 1;
+                    }
+                    if eqs == level && self.peek_byte() == b']' {
+                        self.next_byte();
                         break;
                     }
+                    s.push(b']');
+                    s.extend(std::iter::repeat(b'=').take(eqs));
                 }
+                byt => s.push(byt),
             }
         }
+        Some(s)
+    }
+}
+
+// lets the lexer compose with the wider Rust ecosystem (collect, filter,
+// map, Peekable, ...); routed through the same `next()` used by the
+// hand-written parser, so the `ahead` buffer stays consistent
+impl<R: Read> Iterator for Lex<R> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.next().token;
+        if token == Token::Eos {
+            None
+        } else {
+            Some(token)
+        }
+    }
+}
+
+// a pure token producer for tooling that just wants the stream, without
+// constructing the parser state
+pub fn tokenize<R: Read>(input: R) -> impl Iterator<Item = Token> {
+    Lex::new(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn span_start_skips_leading_whitespace() {
+        let mut lex = Lex::new("   foo".as_bytes());
+        let tok = lex.next();
+        assert_eq!(tok.token, Token::Name("foo".to_string()));
+        assert_eq!(tok.start, Pos { line: 1, column: 4, offset: 3 });
+    }
+
+    #[test]
+    fn span_start_skips_leading_comment() {
+        let mut lex = Lex::new("--comment\nfoo".as_bytes());
+        let tok = lex.next();
+        assert_eq!(tok.token, Token::Name("foo".to_string()));
+        assert_eq!(tok.start, Pos { line: 2, column: 1, offset: 10 });
+    }
+
+    #[test]
+    fn hex_float_exponent_with_no_mantissa_digits_is_malformed() {
+        let mut lex = Lex::new("0xp3".as_bytes());
+        assert_eq!(lex.next().token, Token::Error(LexError::MalformedNumber));
+
+        let mut lex = Lex::new("0x.p3".as_bytes());
+        assert_eq!(lex.next().token, Token::Error(LexError::MalformedNumber));
+    }
+
+    #[test]
+    fn hex_float_exponent_with_mantissa_digits_is_valid() {
+        let mut lex = Lex::new("0x1p3".as_bytes());
+        assert_eq!(lex.next().token, Token::Float(8.0));
+
+        let mut lex = Lex::new("0x.1p3".as_bytes());
+        assert_eq!(lex.next().token, Token::Float(0.0625 * 8.0));
+    }
+
+    #[test]
+    fn hex_number_rejects_trailing_identifier_char() {
+        let mut lex = Lex::new("0x1foo".as_bytes());
+        assert_eq!(lex.next().token, Token::Error(LexError::MalformedNumber));
+
+        let mut lex = Lex::new("0x.foo".as_bytes());
+        assert_eq!(lex.next().token, Token::Error(LexError::MalformedNumber));
+    }
+
+    #[test]
+    fn hex_dot_with_no_digits_at_all_is_malformed() {
+        let mut lex = Lex::new("0x.".as_bytes());
+        assert_eq!(lex.next().token, Token::Error(LexError::MalformedNumber));
+    }
+
+    #[test]
+    fn long_bracket_without_second_bracket_surfaces_the_equals_run() {
+        let mut lex = Lex::new("[=foo".as_bytes());
+        assert_eq!(lex.next().token, Token::String(b"=".to_vec()));
+        assert_eq!(lex.errors(), &[(LexError::UnfinishedLongBracket, Pos { line: 1, column: 3, offset: 2 })]);
+        assert_eq!(lex.next().token, Token::Name("foo".to_string()));
+    }
+
+    #[test]
+    fn long_bracket_without_any_equals_falls_back_to_squrl() {
+        let mut lex = Lex::new("[foo".as_bytes());
+        assert_eq!(lex.next().token, Token::SqurL);
+        assert!(lex.errors().is_empty());
+        assert_eq!(lex.next().token, Token::Name("foo".to_string()));
+    }
+
+    #[test]
+    fn long_bracket_rejects_mismatched_closing_level() {
+        // the "]=" doesn't match the opening "==" level, so it's literal
+        // body text; only the following "]==]" actually closes it
+        let mut lex = Lex::new("[==[hi]=]==]".as_bytes());
+        assert_eq!(lex.next().token, Token::String(b"hi]=".to_vec()));
+    }
+
+    #[test]
+    fn string_escape_decimal() {
+        let mut lex = Lex::new(r#""\65\66""#.as_bytes());
+        assert_eq!(lex.next().token, Token::String(b"AB".to_vec()));
+    }
+
+    #[test]
+    fn string_escape_decimal_rejects_overflow() {
+        let mut lex = Lex::new(r#""\999""#.as_bytes());
+        assert_eq!(lex.next().token, Token::Error(LexError::InvalidEscape));
+    }
+
+    #[test]
+    fn string_escape_hex() {
+        let mut lex = Lex::new(r#""\x41\x42""#.as_bytes());
+        assert_eq!(lex.next().token, Token::String(b"AB".to_vec()));
+    }
+
+    #[test]
+    fn string_escape_hex_requires_two_digits() {
+        let mut lex = Lex::new(r#""\x4""#.as_bytes());
+        assert_eq!(lex.next().token, Token::Error(LexError::InvalidEscape));
+    }
+
+    #[test]
+    fn string_escape_unicode() {
+        let mut lex = Lex::new(r#""\u{48}\u{49}""#.as_bytes());
+        assert_eq!(lex.next().token, Token::String(b"HI".to_vec()));
+    }
+
+    #[test]
+    fn string_escape_unicode_requires_braces() {
+        let mut lex = Lex::new(r#""\u48""#.as_bytes());
+        assert_eq!(lex.next().token, Token::Error(LexError::InvalidEscape));
+    }
+
+    #[test]
+    fn invalid_escape_resyncs_to_the_closing_quote() {
+        let mut lex = Lex::new(r#""ab\qcd" nextname"#.as_bytes());
+        assert_eq!(lex.next().token, Token::Error(LexError::InvalidEscape));
+        assert_eq!(lex.next().token, Token::Name("nextname".to_string()));
+    }
+
+    #[test]
+    fn unterminated_long_bracket_in_comment_falls_back_to_line_comment() {
+        let mut lex = Lex::new("--[==foo\nbar".as_bytes());
+        assert_eq!(lex.next().token, Token::Name("bar".to_string()));
+    }
+
+    #[test]
+    fn tokenize_collects_the_whole_stream() {
+        let tokens: Vec<Token> = tokenize("local x = 1".as_bytes()).collect();
+        assert_eq!(tokens, vec![
+            Token::Local,
+            Token::Name("x".to_string()),
+            Token::Assign,
+            Token::Integer(1),
+        ]);
+    }
+
+    #[test]
+    fn unrecognized_byte_becomes_unknown_token() {
+        let mut lex = Lex::new("@".as_bytes());
+        assert_eq!(lex.next().token, Token::Unknown(b'@'));
     }
 }